@@ -0,0 +1,26 @@
+//! On-disk shape of an `.alias` document's `alias.json`.
+//!
+//! Kept in its own module with no `gpui` import, so [`crate::codegen`]'s
+//! headless entry points (which parse this type) run without the visual
+//! editor, a `Window` or a `gpui` `App`. The crate itself still depends on
+//! `gpui`, so building it compiles `gpui` either way.
+
+use serde::{Deserialize, Serialize};
+
+use crate::generics::GenericParam;
+
+/// On-disk shape of `alias.json`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AliasDocument {
+    pub name: String,
+    pub target: String,
+    /// Generic type/const/lifetime parameters declared on this alias.
+    /// Absent (and treated as empty) on documents saved before generics
+    /// support was added.
+    #[serde(default)]
+    pub generics: Vec<GenericParam>,
+    /// Additional free-form `where` bounds beyond the ones implied by each
+    /// generic's own `bounds`, e.g. `"T::Item: Clone"`.
+    #[serde(default, rename = "where")]
+    pub where_clauses: Vec<String>,
+}
@@ -0,0 +1,292 @@
+//! Project-wide registry of `.alias` definitions, used to resolve a block
+//! that names another alias (e.g. a `Vec<UserId>` block where `UserId` is
+//! itself declared by a sibling `.alias` folder) rather than a concrete
+//! type.
+//!
+//! Mirrors Zellij's `RunPluginOrAlias` + `populate_run_plugin_if_needed`:
+//! references are kept as a bare name in the serialized document and are
+//! only expanded to their concrete target lazily, when the editor loads or
+//! when the referenced alias is saved.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::alias_document::AliasDocument;
+use crate::generics::GenericParam;
+
+/// A discovered `.alias` folder's on-disk definition.
+#[derive(Debug, Clone)]
+pub struct AliasDefinition {
+    pub name: String,
+    pub target: String,
+    pub generics: Vec<GenericParam>,
+    pub where_clauses: Vec<String>,
+    pub path: PathBuf,
+}
+
+/// Surfaced to the editor instead of recursing forever when an alias-of-
+/// alias chain doesn't resolve.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AliasResolutionError {
+    /// The alias names visited, in order, ending with the one that closes
+    /// the cycle back to its start.
+    Cycle(Vec<String>),
+    /// The referenced alias name isn't defined anywhere in the project.
+    NotFound(String),
+}
+
+impl std::fmt::Display for AliasResolutionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AliasResolutionError::Cycle(chain) => {
+                write!(f, "alias cycle detected: {}", chain.join(" -> "))
+            }
+            AliasResolutionError::NotFound(name) => {
+                write!(f, "no alias named `{name}` found in this project")
+            }
+        }
+    }
+}
+
+/// Index of every `.alias` folder in a project, keyed by declared name, so
+/// `TypeRef::AliasName` references can be expanded lazily.
+#[derive(Debug, Clone, Default)]
+pub struct AliasRegistry {
+    definitions: HashMap<String, AliasDefinition>,
+}
+
+impl AliasRegistry {
+    /// Walks `project_root` for `*.alias` folders (each containing an
+    /// `alias.json`) and indexes them by their declared `name`.
+    pub fn scan_project(project_root: &Path) -> Self {
+        let mut definitions = HashMap::new();
+        Self::scan_dir(project_root, &mut definitions);
+        Self { definitions }
+    }
+
+    fn scan_dir(dir: &Path, out: &mut HashMap<String, AliasDefinition>) {
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if !path.is_dir() {
+                continue;
+            }
+            if path.extension().map(|e| e == "alias").unwrap_or(false) {
+                if let Some(def) = Self::load_definition(&path) {
+                    out.insert(def.name.clone(), def);
+                }
+            } else {
+                Self::scan_dir(&path, out);
+            }
+        }
+    }
+
+    fn load_definition(alias_dir: &Path) -> Option<AliasDefinition> {
+        let marker = alias_dir.join("alias.json");
+        let contents = std::fs::read_to_string(&marker).ok()?;
+        let doc: AliasDocument = serde_json::from_str(&contents).ok()?;
+        Some(AliasDefinition {
+            name: doc.name,
+            target: doc.target,
+            generics: doc.generics,
+            where_clauses: doc.where_clauses,
+            path: alias_dir.to_path_buf(),
+        })
+    }
+
+    pub fn get(&self, name: &str) -> Option<&AliasDefinition> {
+        self.definitions.get(name)
+    }
+
+    /// Every indexed definition, for tools (e.g. headless codegen) that
+    /// need to walk the whole project rather than resolve a single name.
+    pub fn all(&self) -> impl Iterator<Item = &AliasDefinition> {
+        self.definitions.values()
+    }
+
+    /// Re-reads a single alias after it's been saved elsewhere, so open
+    /// documents that depend on it see the change without a full rescan.
+    pub fn refresh(&mut self, alias_dir: &Path) {
+        if let Some(def) = Self::load_definition(alias_dir) {
+            self.definitions.insert(def.name.clone(), def);
+        }
+    }
+
+    /// Resolves every bare alias-name token appearing anywhere in `text`,
+    /// not just a whole-string match, so a flattened block label like
+    /// `Vec<UserId>` (a single leaf, since block composition only records
+    /// one string per document) still picks up `UserId`'s own resolution.
+    ///
+    /// `locals` is the set of generic parameter names declared on the
+    /// document `text` belongs to (e.g. `["T", "E"]` for
+    /// `type Result2<T, E> = Result<T, E>;`). A token matching one of them
+    /// is always treated as the local parameter, never substituted, even
+    /// if a project alias happens to share its name (a `T`/`E`/`N` alias
+    /// is exactly the kind of name `ConstructorPalette::generic_options`
+    /// offers for a document's own generics).
+    pub fn resolve_in_text(&self, text: &str, locals: &[String]) -> Result<String, AliasResolutionError> {
+        let mut chain = Vec::new();
+        self.resolve_text_inner(text, &mut chain, locals)
+    }
+
+    /// Like [`Self::resolve_in_text`], but threads the in-progress `chain`
+    /// of alias names through composite targets too, so a cycle built
+    /// through a container type (`A` -> `Vec<B>`, `B` -> `Vec<A>`) is
+    /// caught just like a direct name-to-name cycle instead of silently
+    /// emitting `Vec<Vec<A>>`-style garbage with a dangling identifier.
+    fn resolve_text_inner(
+        &self,
+        text: &str,
+        chain: &mut Vec<String>,
+        locals: &[String],
+    ) -> Result<String, AliasResolutionError> {
+        let mut out = String::with_capacity(text.len());
+        let mut chars = text.char_indices().peekable();
+        while let Some((start, c)) = chars.next() {
+            if !(c.is_ascii_alphabetic() || c == '_') {
+                out.push(c);
+                continue;
+            }
+            let mut end = start + c.len_utf8();
+            while let Some(&(i, c2)) = chars.peek() {
+                if c2.is_ascii_alphanumeric() || c2 == '_' {
+                    end = i + c2.len_utf8();
+                    chars.next();
+                } else {
+                    break;
+                }
+            }
+            let token = &text[start..end];
+            if locals.iter().any(|local| local == token) {
+                out.push_str(token);
+            } else if self.definitions.contains_key(token) {
+                out.push_str(&self.resolve_inner(token, chain)?);
+            } else {
+                out.push_str(token);
+            }
+        }
+        Ok(out)
+    }
+
+    /// Fully expands `name` to a concrete Rust type fragment, following
+    /// alias-of-alias chains, erroring instead of recursing forever if
+    /// `name` is part of a cycle.
+    pub fn resolve(&self, name: &str) -> Result<String, AliasResolutionError> {
+        let mut chain = Vec::new();
+        self.resolve_inner(name, &mut chain)
+    }
+
+    /// Shared recursion for [`Self::resolve`] and [`Self::resolve_text_inner`],
+    /// expanding `def.target` through the composite-aware tokenizer rather
+    /// than only handling the bare-name case, so both resolution and cycle
+    /// detection work for targets like `Vec<UserId>`, not just `UserId`.
+    ///
+    /// `def.target` is re-scanned excluding `def`'s *own* declared generics
+    /// (not the caller's), since those are the names that are local to the
+    /// alias whose target is being expanded at this step of the chain.
+    fn resolve_inner(
+        &self,
+        name: &str,
+        chain: &mut Vec<String>,
+    ) -> Result<String, AliasResolutionError> {
+        if chain.iter().any(|visited| visited == name) {
+            chain.push(name.to_string());
+            return Err(AliasResolutionError::Cycle(chain.clone()));
+        }
+        chain.push(name.to_string());
+        let def = self
+            .definitions
+            .get(name)
+            .ok_or_else(|| AliasResolutionError::NotFound(name.to_string()))?;
+        let def_locals: Vec<String> = def.generics.iter().map(|g| g.name.clone()).collect();
+        let resolved = self.resolve_text_inner(&def.target, chain, &def_locals)?;
+        chain.pop();
+        Ok(resolved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn registry(defs: &[(&str, &str, &[&str])]) -> AliasRegistry {
+        let mut definitions = HashMap::new();
+        for (name, target, generics) in defs {
+            definitions.insert(
+                name.to_string(),
+                AliasDefinition {
+                    name: name.to_string(),
+                    target: target.to_string(),
+                    generics: generics.iter().map(|g| GenericParam::type_param(*g)).collect(),
+                    where_clauses: Vec::new(),
+                    path: PathBuf::new(),
+                },
+            );
+        }
+        AliasRegistry { definitions }
+    }
+
+    #[test]
+    fn resolves_a_direct_chain() {
+        let reg = registry(&[("UserId", "u64", &[]), ("Ids", "Vec<UserId>", &[])]);
+        assert_eq!(reg.resolve("Ids").unwrap(), "Vec<u64>");
+    }
+
+    #[test]
+    fn detects_a_self_cycle() {
+        let reg = registry(&[("A", "A", &[])]);
+        assert_eq!(reg.resolve("A"), Err(AliasResolutionError::Cycle(vec!["A".into(), "A".into()])));
+    }
+
+    #[test]
+    fn detects_a_two_hop_cycle() {
+        let reg = registry(&[("A", "B", &[]), ("B", "A", &[])]);
+        assert_eq!(
+            reg.resolve("A"),
+            Err(AliasResolutionError::Cycle(vec!["A".into(), "B".into(), "A".into()]))
+        );
+    }
+
+    #[test]
+    fn detects_a_cycle_through_a_composite_target() {
+        let reg = registry(&[("A", "Vec<B>", &[]), ("B", "Vec<A>", &[])]);
+        assert_eq!(
+            reg.resolve("A"),
+            Err(AliasResolutionError::Cycle(vec!["A".into(), "B".into(), "A".into()]))
+        );
+    }
+
+    #[test]
+    fn surfaces_a_missing_reference() {
+        let reg = registry(&[("Ids", "Vec<UserId>", &[])]);
+        assert_eq!(reg.resolve("Ids"), Err(AliasResolutionError::NotFound("UserId".into())));
+    }
+
+    #[test]
+    fn local_generic_param_is_not_confused_with_a_same_named_alias() {
+        // An alias literally named `T` exists in the project, but `T` is
+        // also this (unrelated) document's own generic parameter: it must
+        // never be substituted, only the `MyError` reference should be.
+        let reg = registry(&[("T", "i32", &[]), ("MyError", "std::io::Error", &[])]);
+        let locals = vec!["T".to_string()];
+        assert_eq!(
+            reg.resolve_in_text("Result<T, MyError>", &locals).unwrap(),
+            "Result<T, std::io::Error>"
+        );
+    }
+
+    #[test]
+    fn an_aliass_own_generics_are_excluded_from_its_own_target_expansion() {
+        // `Result2<T> = Result<T, MyError>` declares its own `T`; resolving
+        // a reference to `Result2` must not substitute that `T` even though
+        // a project alias named `T` exists.
+        let reg = registry(&[
+            ("T", "i32", &[]),
+            ("MyError", "std::io::Error", &[]),
+            ("Result2", "Result<T, MyError>", &["T"]),
+        ]);
+        assert_eq!(reg.resolve("Result2").unwrap(), "Result<T, std::io::Error>");
+    }
+}
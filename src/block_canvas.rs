@@ -0,0 +1,167 @@
+//! The interactive canvas that arranges [`TypeBlock`]s into a composed type.
+
+use std::collections::HashMap;
+
+use gpui::*;
+
+use crate::alias_registry::{AliasRegistry, AliasResolutionError};
+use crate::diagnostics::{DiagnosticLevel, SpanMap};
+use crate::type_block::{BlockId, TypeBlock};
+
+/// Tracks an in-progress drag originating from the canvas or a palette.
+#[derive(Debug, Clone)]
+pub enum DragState {
+    /// Dragging an existing block to a new position.
+    MovingBlock { id: BlockId, origin: Point<Pixels> },
+    /// Dragging a new block in from a palette; not yet placed on the canvas.
+    PlacingNew { label: String },
+}
+
+/// Where a drag would land if released right now.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DropTarget {
+    Canvas(Point<Pixels>),
+    Block(BlockId),
+    None,
+}
+
+/// Renders the block graph and handles drag/drop composition.
+pub struct BlockCanvas {
+    pub blocks: HashMap<BlockId, TypeBlock>,
+    pub roots: Vec<BlockId>,
+    pub drag: Option<DragState>,
+    pub drop_target: DropTarget,
+    next_id: u64,
+    diagnostics: HashMap<BlockId, DiagnosticLevel>,
+    focus_handle: FocusHandle,
+}
+
+impl BlockCanvas {
+    pub fn new(cx: &mut Context<Self>) -> Self {
+        Self {
+            blocks: HashMap::new(),
+            roots: Vec::new(),
+            drag: None,
+            drop_target: DropTarget::None,
+            next_id: 0,
+            diagnostics: HashMap::new(),
+            focus_handle: cx.focus_handle(),
+        }
+    }
+
+    pub fn insert_root(&mut self, label: impl Into<String>, position: (f32, f32)) -> BlockId {
+        let id = BlockId::next(&mut self.next_id);
+        self.blocks.insert(id, TypeBlock::new(id, label, position));
+        self.roots.push(id);
+        id
+    }
+
+    pub fn remove(&mut self, id: BlockId) {
+        self.blocks.remove(&id);
+        self.roots.retain(|r| *r != id);
+        for block in self.blocks.values_mut() {
+            block.children.retain(|c| *c != id);
+        }
+    }
+
+    /// Renders the composed `Target` fragment of `type Name = Target;` from
+    /// the current root blocks, in canvas order.
+    pub fn render_type_expr(&self) -> String {
+        self.roots
+            .iter()
+            .filter_map(|id| self.blocks.get(id))
+            .map(|b| b.render_fragment(&self.blocks))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// Like [`Self::render_type_expr`], but expands any block that names
+    /// another project `.alias` to its fully-resolved concrete type.
+    ///
+    /// `locals` is the document's own declared generic parameter names, so
+    /// a leaf block that happens to be named e.g. `T` is never confused
+    /// with a project alias also named `T` (see [`TypeBlock::classify`]).
+    pub fn render_type_expr_resolved(
+        &self,
+        registry: &AliasRegistry,
+        locals: &[String],
+    ) -> Result<String, AliasResolutionError> {
+        let mut parts = Vec::with_capacity(self.roots.len());
+        for id in &self.roots {
+            if let Some(block) = self.blocks.get(id) {
+                parts.push(block.render_fragment_resolved(&self.blocks, registry, locals)?);
+            }
+        }
+        Ok(parts.join(", "))
+    }
+
+    /// Like [`Self::render_type_expr_resolved`], but also returns a
+    /// [`SpanMap`] so the [`crate::diagnostics::DiagnosticsEngine`] can map
+    /// compiler diagnostics back onto individual blocks. Spans are recorded
+    /// against this resolved text (alias names expanded), since that's the
+    /// text that actually gets embedded in the declaration sent to `rustc`.
+    pub fn render_type_expr_resolved_spanned(
+        &self,
+        registry: &AliasRegistry,
+        locals: &[String],
+    ) -> Result<(String, SpanMap), AliasResolutionError> {
+        let mut out = String::new();
+        let mut map = SpanMap::default();
+        for (i, id) in self.roots.iter().enumerate() {
+            if i > 0 {
+                out.push_str(", ");
+            }
+            if let Some(block) = self.blocks.get(id) {
+                block.render_fragment_resolved_spanned(&self.blocks, registry, locals, &mut out, &mut |id, range| {
+                    map.insert(range, id);
+                })?;
+            }
+        }
+        Ok((out, map))
+    }
+
+    /// Replaces the badges shown on blocks with the latest validation pass.
+    pub fn set_diagnostics(&mut self, diagnostics: &[crate::diagnostics::BlockDiagnostic]) {
+        self.diagnostics.clear();
+        for diag in diagnostics {
+            let Some(id) = diag.block_id else { continue };
+            let worse = match self.diagnostics.get(&id) {
+                Some(DiagnosticLevel::Error) => continue,
+                _ => diag.level,
+            };
+            self.diagnostics.insert(id, worse);
+        }
+    }
+}
+
+impl Focusable for BlockCanvas {
+    fn focus_handle(&self, _cx: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for BlockCanvas {
+    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
+        div()
+            .track_focus(&self.focus_handle)
+            .relative()
+            .size_full()
+            .bg(rgb(0x1E1E22))
+            .children(self.roots.iter().filter_map(|id| self.blocks.get(id)).map(|block| {
+                let badge = self.diagnostics.get(&block.id).map(|level| match level {
+                    DiagnosticLevel::Error => rgb(0xE5484D),
+                    DiagnosticLevel::Warning => rgb(0xE5C53D),
+                });
+                div()
+                    .absolute()
+                    .left(px(block.position.0))
+                    .top(px(block.position.1))
+                    .px_2()
+                    .py_1()
+                    .rounded_md()
+                    .bg(rgb(0x33334A))
+                    .when_some(badge, |el, color| el.border_2().border_color(color))
+                    .child(block.label.clone())
+            }))
+    }
+}
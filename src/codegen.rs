@@ -0,0 +1,96 @@
+//! GUI-free core: turns a document's name, target, generics, and `where`
+//! bounds into the `type Name<...> = Target where ...;` declaration it
+//! produces.
+//!
+//! This is the shared logic behind both the interactive
+//! [`crate::visual_editor::VisualAliasEditor`] and the headless entry
+//! points below, so a `build.rs` or CLI can turn a directory of `.alias`
+//! definitions into generated code without ever instantiating [`gpui`] or
+//! the visual editor.
+
+use std::path::Path;
+
+use crate::alias_document::AliasDocument;
+use crate::alias_registry::{AliasDefinition, AliasRegistry, AliasResolutionError};
+use crate::generics::{render_param_list, render_where_clause, GenericParam};
+
+/// Renders `type name<generics> = target where ...;`, resolving `target`
+/// against `registry` first if it names another alias.
+pub fn generate_declaration(
+    name: &str,
+    target: &str,
+    generics: &[GenericParam],
+    where_clauses: &[String],
+    registry: &AliasRegistry,
+) -> Result<String, AliasResolutionError> {
+    generate_declaration_with_prefix_len(name, target, generics, where_clauses, registry)
+        .map(|(declaration, _prefix_len)| declaration)
+}
+
+/// Like [`generate_declaration`], but also returns the byte length of the
+/// `"type name<generics> = "` prefix. Callers that already have a
+/// [`crate::diagnostics::SpanMap`] built against the bare `target` fragment
+/// (rather than the full declaration) can shift it by this amount so its
+/// ranges line up with `rustc`'s byte offsets into the text this function
+/// returns.
+pub fn generate_declaration_with_prefix_len(
+    name: &str,
+    target: &str,
+    generics: &[GenericParam],
+    where_clauses: &[String],
+    registry: &AliasRegistry,
+) -> Result<(String, usize), AliasResolutionError> {
+    // `target` is a flattened single string (the form stored in
+    // `alias.json`), so a composite type that embeds an alias reference
+    // (e.g. `Vec<UserId>`) needs the same token-aware resolution
+    // `TypeBlock::render_fragment_resolved` uses, not just a whole-string
+    // match against another alias's bare name. `generics`' own names are
+    // excluded so a local type parameter (e.g. `T` in `Result<T, E>`) is
+    // never confused with a project alias that happens to share its name.
+    let locals: Vec<String> = generics.iter().map(|g| g.name.clone()).collect();
+    let resolved_target = registry.resolve_in_text(target, &locals)?;
+    let prefix = format!("type {}{} = ", name, render_param_list(generics));
+    let declaration = format!(
+        "{}{}{};",
+        prefix,
+        resolved_target,
+        render_where_clause(generics, where_clauses),
+    );
+    Ok((declaration, prefix.len()))
+}
+
+fn generate_for_definition(
+    def: &AliasDefinition,
+    registry: &AliasRegistry,
+) -> Result<String, AliasResolutionError> {
+    generate_declaration(&def.name, &def.target, &def.generics, &def.where_clauses, registry)
+}
+
+/// Reads a single `.alias` folder's `alias.json` and generates the
+/// declaration it produces, resolving alias-of-alias references against
+/// the rest of `project_root`.
+pub fn generate_from_folder(alias_dir: &Path, project_root: &Path) -> anyhow::Result<String> {
+    let marker = alias_dir.join("alias.json");
+    let contents = std::fs::read_to_string(&marker)?;
+    let doc: AliasDocument = serde_json::from_str(&contents)?;
+    let registry = AliasRegistry::scan_project(project_root);
+    generate_declaration(&doc.name, &doc.target, &doc.generics, &doc.where_clauses, &registry)
+        .map_err(|err| anyhow::anyhow!(err.to_string()))
+}
+
+/// Walks `project_root` for every `.alias` folder and generates one
+/// declaration per line, in a deterministic (name-sorted) order, suitable
+/// for writing to an `OUT_DIR` file from `build.rs`.
+pub fn generate_project_module(project_root: &Path) -> anyhow::Result<String> {
+    let registry = AliasRegistry::scan_project(project_root);
+    let mut definitions: Vec<&AliasDefinition> = registry.all().collect();
+    definitions.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let mut declarations = Vec::with_capacity(definitions.len());
+    for def in definitions {
+        declarations.push(
+            generate_for_definition(def, &registry).map_err(|err| anyhow::anyhow!(err.to_string()))?,
+        );
+    }
+    Ok(declarations.join("\n"))
+}
@@ -0,0 +1,107 @@
+//! Palette used to pick the top-level constructor (the outermost block) for
+//! a new `.alias` document, e.g. "start from a bare type" vs "start from a
+//! generic container".
+
+use gpui::*;
+
+use crate::generics::GenericParam;
+
+/// Emitted when the user picks an entry from the [`ConstructorPalette`].
+#[derive(Debug, Clone)]
+pub struct TypeSelected {
+    pub label: String,
+}
+
+/// Emitted when the user adds a generic parameter from the "Add generic"
+/// section of the [`ConstructorPalette`].
+#[derive(Debug, Clone)]
+pub struct GenericParamAdded(pub GenericParam);
+
+/// Modal-style palette listing the types a new document can be seeded with,
+/// plus quick actions for declaring a generic/const/lifetime parameter on
+/// the current document.
+pub struct ConstructorPalette {
+    options: Vec<String>,
+    generic_options: Vec<GenericParam>,
+    focus_handle: FocusHandle,
+}
+
+impl ConstructorPalette {
+    pub fn new(cx: &mut Context<Self>) -> Self {
+        Self {
+            options: vec![
+                "i32".into(),
+                "String".into(),
+                "Vec<".into(),
+                "Option<".into(),
+                "Result<".into(),
+            ],
+            generic_options: vec![
+                GenericParam::type_param("T"),
+                GenericParam::type_param("E"),
+                GenericParam::const_param("N", "usize"),
+                GenericParam::lifetime("'a"),
+            ],
+            focus_handle: cx.focus_handle(),
+        }
+    }
+
+    pub fn choose(&self, label: &str, cx: &mut Context<Self>) {
+        if self.options.iter().any(|o| o == label) {
+            cx.emit(TypeSelected {
+                label: label.to_string(),
+            });
+        }
+    }
+
+    pub fn add_generic(&self, name: &str, cx: &mut Context<Self>) {
+        if let Some(param) = self.generic_options.iter().find(|g| g.name == name) {
+            cx.emit(GenericParamAdded(param.clone()));
+        }
+    }
+}
+
+impl EventEmitter<TypeSelected> for ConstructorPalette {}
+impl EventEmitter<GenericParamAdded> for ConstructorPalette {}
+
+impl Focusable for ConstructorPalette {
+    fn focus_handle(&self, _cx: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for ConstructorPalette {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        div()
+            .track_focus(&self.focus_handle)
+            .flex()
+            .flex_col()
+            .gap_1()
+            .p_2()
+            .children(self.options.iter().cloned().map(|label| {
+                let clicked = label.clone();
+                div()
+                    .px_2()
+                    .py_1()
+                    .rounded_md()
+                    .hover(|s| s.bg(rgb(0x2A2A2E)))
+                    .on_mouse_down(MouseButton::Left, cx.listener(move |this, _, _, cx| {
+                        this.choose(&clicked, cx);
+                    }))
+                    .child(label)
+            }))
+            .child(div().h(px(1.0)).bg(rgb(0x3A3A3E)))
+            .children(self.generic_options.iter().cloned().map(|param| {
+                let name = param.name.clone();
+                div()
+                    .px_2()
+                    .py_1()
+                    .rounded_md()
+                    .hover(|s| s.bg(rgb(0x2A2A2E)))
+                    .on_mouse_down(MouseButton::Left, cx.listener(move |this, _, _, cx| {
+                        this.add_generic(&name, cx);
+                    }))
+                    .child(format!("+ {}", param.name))
+            }))
+    }
+}
@@ -0,0 +1,209 @@
+//! Background `rustc`/`cargo check` validation of the generated type alias.
+//!
+//! Modeled on pepper's process-callback lifecycle
+//! (`on_process_spawned`/`on_process_output`/`on_process_exit`): every time
+//! the block graph changes (or on save) we write the generated
+//! `type Name = Target;` into a scratch crate and spawn `rustc` as an async
+//! child process with `--error-format=json`, streaming and parsing its
+//! diagnostics line-by-line as they arrive rather than waiting for exit.
+//! A new edit cancels whatever pass is still in flight.
+
+use std::path::PathBuf;
+use std::process::Stdio;
+use std::sync::{Arc, Mutex};
+
+use gpui::*;
+use serde::Deserialize;
+use smol::io::{AsyncBufReadExt, BufReader};
+use smol::process::{Child, Command};
+
+use crate::type_block::BlockId;
+
+/// Severity of a single diagnostic, mirrored from rustc's JSON `level` field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticLevel {
+    Error,
+    Warning,
+}
+
+/// One diagnostic, mapped back onto the block whose fragment produced the
+/// offending byte span (if the span falls inside the generated declaration
+/// at all; diagnostics about the surrounding scratch crate have no block).
+#[derive(Debug, Clone)]
+pub struct BlockDiagnostic {
+    pub block_id: Option<BlockId>,
+    pub level: DiagnosticLevel,
+    pub message: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct RustcSpan {
+    byte_start: usize,
+}
+
+#[derive(Debug, Deserialize)]
+struct RustcMessage {
+    message: String,
+    level: String,
+    #[serde(default)]
+    spans: Vec<RustcSpan>,
+}
+
+/// Maps byte ranges within the generated scratch source back to the
+/// [`BlockId`] whose fragment occupies them, built alongside codegen.
+#[derive(Debug, Clone, Default)]
+pub struct SpanMap {
+    ranges: Vec<(std::ops::Range<usize>, BlockId)>,
+}
+
+impl SpanMap {
+    pub fn insert(&mut self, range: std::ops::Range<usize>, id: BlockId) {
+        self.ranges.push((range, id));
+    }
+
+    pub fn block_for_byte(&self, byte: usize) -> Option<BlockId> {
+        self.ranges
+            .iter()
+            .find(|(range, _)| range.contains(&byte))
+            .map(|(_, id)| *id)
+    }
+
+    /// Shifts every recorded range forward by `offset` bytes, turning ranges
+    /// relative to a bare fragment into ranges relative to the full text
+    /// that fragment was embedded in (e.g. after a `"type Name<...> = "`
+    /// prefix is prepended for the declaration actually sent to `rustc`).
+    pub fn shift(&mut self, offset: usize) {
+        for (range, _) in &mut self.ranges {
+            range.start += offset;
+            range.end += offset;
+        }
+    }
+}
+
+/// Owns the debounced, cancellable validation pass for one open document.
+pub struct DiagnosticsEngine {
+    scratch_dir: PathBuf,
+    generation: u64,
+    diagnostics: Vec<BlockDiagnostic>,
+    /// The `rustc` child spawned by the in-flight pass, if any, so a newer
+    /// edit can kill it outright instead of merely dropping its future
+    /// (which `smol`/`async-process` would otherwise leave running
+    /// unreaped in the background).
+    running_child: Arc<Mutex<Option<Child>>>,
+    _task: Option<Task<()>>,
+}
+
+impl DiagnosticsEngine {
+    pub fn new(scratch_dir: PathBuf) -> Self {
+        Self {
+            scratch_dir,
+            generation: 0,
+            diagnostics: Vec::new(),
+            running_child: Arc::new(Mutex::new(None)),
+            _task: None,
+        }
+    }
+
+    pub fn diagnostics(&self) -> &[BlockDiagnostic] {
+        &self.diagnostics
+    }
+
+    /// Cancels any in-flight validation (killing its `rustc` child outright,
+    /// not just dropping its future) and spawns a new one for `declaration`.
+    /// `spans` is used to attach each parsed diagnostic back to the
+    /// originating block.
+    pub fn revalidate(&mut self, declaration: String, spans: SpanMap, cx: &mut Context<Self>) {
+        self.generation += 1;
+        let generation = self.generation;
+        let scratch_dir = self.scratch_dir.clone();
+        let running_child = self.running_child.clone();
+
+        // Dropping `_task` below only stops polling this pass's future; the
+        // `rustc` child it already spawned keeps running in the background
+        // unless we kill it ourselves. Do that, and reap it, off the main
+        // task so this call doesn't have to block on it.
+        if let Some(mut stale_child) = running_child.lock().unwrap().take() {
+            cx.background_spawn(async move {
+                let _ = stale_child.kill();
+                let _ = stale_child.status().await;
+            })
+            .detach();
+        }
+
+        // Each generation gets its own scratch file name, so a still-dying
+        // superseded `rustc` process can never race this pass's write to
+        // (or read of) the file a newer edit is using.
+        let scratch_file = scratch_dir.join(format!("lib-{generation}.rs"));
+        let scratch_rlib = scratch_dir.join(format!("scratch-{generation}.rlib"));
+
+        self._task = Some(cx.spawn(async move |this, cx| {
+            if smol::fs::create_dir_all(&scratch_dir).await.is_err() {
+                return;
+            }
+            if smol::fs::write(&scratch_file, declaration.as_bytes()).await.is_err() {
+                return;
+            }
+
+            let mut child = match Command::new("rustc")
+                .arg("--edition=2021")
+                .arg("--crate-type=lib")
+                .arg("--error-format=json")
+                .arg("-o")
+                .arg(&scratch_rlib)
+                .arg(&scratch_file)
+                .stdout(Stdio::null())
+                .stderr(Stdio::piped())
+                .spawn()
+            {
+                Ok(child) => child,
+                Err(err) => {
+                    log::warn!("alias-editor: failed to spawn rustc for validation: {err}");
+                    let _ = smol::fs::remove_file(&scratch_file).await;
+                    return;
+                }
+            };
+
+            let stderr = child.stderr.take().expect("stderr was piped");
+            *running_child.lock().unwrap() = Some(child);
+
+            let mut lines = BufReader::new(stderr).lines();
+            let mut parsed = Vec::new();
+            while let Some(Ok(line)) = lines.next().await {
+                let Ok(msg) = serde_json::from_str::<RustcMessage>(&line) else {
+                    continue;
+                };
+                let level = match msg.level.as_str() {
+                    "error" => DiagnosticLevel::Error,
+                    _ => DiagnosticLevel::Warning,
+                };
+                let block_id = msg
+                    .spans
+                    .first()
+                    .and_then(|span| spans.block_for_byte(span.byte_start));
+                parsed.push(BlockDiagnostic {
+                    block_id,
+                    level,
+                    message: msg.message,
+                });
+            }
+            // If a newer edit already killed and reaped this child, it's no
+            // longer in `running_child`; nothing left to wait on.
+            if let Some(mut child) = running_child.lock().unwrap().take() {
+                let _ = child.status().await;
+            }
+            let _ = smol::fs::remove_file(&scratch_file).await;
+            let _ = smol::fs::remove_file(&scratch_rlib).await;
+
+            this.update(cx, |this, cx| {
+                // A newer edit superseded this pass while rustc was still
+                // running; discard the now-stale results.
+                if this.generation != generation {
+                    return;
+                }
+                this.diagnostics = parsed;
+                cx.notify();
+            })
+            .ok();
+        }));
+    }
+}
@@ -0,0 +1,114 @@
+//! Generic type parameters, const generics, and lifetime parameters that
+//! can be declared on an `.alias` document, plus optional `where` bounds.
+//!
+//! Serialized into `alias.json` as a `generics: [...]` and `where: [...]`
+//! section alongside the existing `name`/`target` fields.
+
+use serde::{Deserialize, Serialize};
+
+/// The kind of generic parameter a [`GenericParam`] declares.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GenericParamKind {
+    /// A type parameter, e.g. `T`.
+    Type,
+    /// A const generic, e.g. `const N: usize`. The payload is the const's
+    /// own type (`usize` above).
+    Const(String),
+    /// A lifetime parameter, e.g. `'a`.
+    Lifetime,
+}
+
+/// One generic parameter declared on the alias.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GenericParam {
+    pub name: String,
+    pub kind: GenericParamKind,
+    /// Trait bounds attached to a type parameter (e.g. `Clone`, `Send`).
+    /// Ignored for lifetimes and const generics.
+    #[serde(default)]
+    pub bounds: Vec<String>,
+}
+
+impl GenericParam {
+    pub fn type_param(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            kind: GenericParamKind::Type,
+            bounds: Vec::new(),
+        }
+    }
+
+    pub fn const_param(name: impl Into<String>, ty: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            kind: GenericParamKind::Const(ty.into()),
+            bounds: Vec::new(),
+        }
+    }
+
+    pub fn lifetime(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            kind: GenericParamKind::Lifetime,
+            bounds: Vec::new(),
+        }
+    }
+
+    /// Renders this parameter's declaration-site syntax, e.g. `T`,
+    /// `const N: usize`, or `'a`.
+    fn render_param(&self) -> String {
+        match &self.kind {
+            GenericParamKind::Type => self.name.clone(),
+            GenericParamKind::Const(ty) => format!("const {}: {}", self.name, ty),
+            GenericParamKind::Lifetime => self.name.clone(),
+        }
+    }
+
+    /// The bound expression this parameter contributes to a `where` clause
+    /// (e.g. `T: Clone + Send`), or `None` if it has no bounds or isn't a
+    /// type parameter.
+    fn where_bound(&self) -> Option<String> {
+        if self.bounds.is_empty() || self.kind != GenericParamKind::Type {
+            None
+        } else {
+            Some(format!("{}: {}", self.name, self.bounds.join(" + ")))
+        }
+    }
+}
+
+/// Renders the full `<...>` parameter list for a declaration. Rust requires
+/// lifetime parameters to precede type/const ones, so those are sorted to
+/// the front regardless of declaration order (stable, so otherwise-equal
+/// parameters keep the order they were added in); bounds are intentionally
+/// left out of this list and rendered into the `where` clause instead,
+/// keeping the declaration head short even with many constrained
+/// parameters.
+pub fn render_param_list(generics: &[GenericParam]) -> String {
+    if generics.is_empty() {
+        return String::new();
+    }
+    let mut ordered: Vec<&GenericParam> = generics.iter().collect();
+    ordered.sort_by_key(|g| !matches!(g.kind, GenericParamKind::Lifetime));
+    format!(
+        "<{}>",
+        ordered
+            .iter()
+            .map(|g| g.render_param())
+            .collect::<Vec<_>>()
+            .join(", ")
+    )
+}
+
+/// Renders a trailing ` where ...` clause from each parameter's own bounds
+/// plus any additional free-form bound strings (e.g. `"T: Default"`), or an
+/// empty string if there are none.
+pub fn render_where_clause(generics: &[GenericParam], extra_bounds: &[String]) -> String {
+    let mut bounds: Vec<String> = generics.iter().filter_map(GenericParam::where_bound).collect();
+    bounds.extend(extra_bounds.iter().cloned());
+    if bounds.is_empty() {
+        String::new()
+    } else {
+        format!(" where {}", bounds.join(", "))
+    }
+}
@@ -22,19 +22,33 @@ use std::collections::HashMap;
 use gpui::*;
 use ui::dock::PanelView;
 
-// Alias Editor modules
+// GUI-free data model and codegen core: none of these modules (nor their
+// dependencies) imports `gpui`.
+pub mod alias_document;
+pub mod generics;
+pub mod alias_registry;
 pub mod type_block;
+pub mod codegen;
+
+// Alias Editor GUI modules (all depend on `gpui`).
 pub mod constructor_palette;
 pub mod block_canvas;
 pub mod visual_editor;
 pub mod type_palette;
+pub mod diagnostics;
+pub mod preview_pane;
 
 // Re-export main types
 pub use visual_editor::{VisualAliasEditor as AliasEditor, ShowTypePickerRequest};
-pub use type_block::{TypeBlock, BlockId};
-pub use constructor_palette::{ConstructorPalette, TypeSelected};
+pub use type_block::{TypeBlock, BlockId, TypeRef};
+pub use constructor_palette::{ConstructorPalette, TypeSelected, GenericParamAdded};
 pub use block_canvas::{BlockCanvas, DragState, DropTarget};
 pub use type_palette::{TypeLibraryPalette, TypeItem};
+pub use diagnostics::{BlockDiagnostic, DiagnosticLevel};
+pub use alias_document::AliasDocument;
+pub use alias_registry::{AliasRegistry, AliasDefinition, AliasResolutionError};
+pub use generics::{GenericParam, GenericParamKind};
+pub use preview_pane::GeneratedCodePreview;
 
 /// Storage for editor instances owned by the plugin
 struct EditorStorage {
@@ -48,6 +62,10 @@ pub struct AliasEditorPlugin {
     /// The main app only gets raw pointers - it NEVER owns the Arc or Box.
     editors: Arc<Mutex<HashMap<usize, EditorStorage>>>,
     next_editor_id: Arc<Mutex<usize>>,
+    /// Every currently-open alias-editor panel, so that saving one document
+    /// can ask the others to [`AliasEditor::refresh_registry`] in case the
+    /// saved file was an alias-of-alias dependency of theirs.
+    open_panels: Arc<Mutex<Vec<Entity<AliasEditor>>>>,
 }
 
 impl Default for AliasEditorPlugin {
@@ -55,6 +73,7 @@ impl Default for AliasEditorPlugin {
         Self {
             editors: Arc::new(Mutex::new(HashMap::new())),
             next_editor_id: Arc::new(Mutex::new(0)),
+            open_panels: Arc::new(Mutex::new(Vec::new())),
         }
     }
 }
@@ -126,6 +145,10 @@ impl EditorPlugin for AliasEditorPlugin {
             // Wrap the panel in Arc - will be shared with main app
             let panel_arc: Arc<dyn ui::dock::PanelView> = Arc::new(panel.clone());
 
+            // Track this panel so other open documents can be asked to
+            // refresh their alias registry when this one is saved.
+            self.open_panels.lock().unwrap().push(panel.clone());
+
             // Clone file_path for logging
             let file_path_for_log = file_path.clone();
 
@@ -133,6 +156,7 @@ impl EditorPlugin for AliasEditorPlugin {
             let wrapper = Box::new(AliasEditorWrapper {
                 panel: panel.into(),
                 file_path,
+                open_panels: self.open_panels.clone(),
             });
 
             // Generate unique ID for this editor
@@ -167,15 +191,50 @@ impl EditorPlugin for AliasEditorPlugin {
         let mut editors = self.editors.lock().unwrap();
         let count = editors.len();
         editors.clear();
+        // `open_panels` holds its own `Entity<AliasEditor>` clone per editor
+        // (see `create_editor`), so it has to be cleared in lockstep with
+        // `editors` above or it keeps every panel alive past unload.
+        self.open_panels.lock().unwrap().clear();
         log::info!("Alias Editor Plugin unloaded (cleaned up {} editors)", count);
     }
 }
 
+impl AliasEditorPlugin {
+    /// Headless entry point: generates the `type Name = Target;`
+    /// declaration a single `.alias` folder produces, without instantiating
+    /// the visual editor. `project_root` is scanned to resolve any
+    /// alias-of-alias references in `alias_dir`.
+    ///
+    /// This is what a `build.rs` or a standalone CLI should call to turn a
+    /// directory of `.alias` definitions into generated Rust code; the
+    /// interactive `create_editor` path above is just one consumer of the
+    /// same [`codegen`] core. [`codegen`] and everything it depends on
+    /// (`alias_document`, `generics`, `alias_registry`, `type_block`)
+    /// imports no `gpui` and needs no `Window` or `App`. This crate as a
+    /// whole (this module included) still depends on `gpui`, so building
+    /// it compiles `gpui` whether [`codegen`] is reached through this type
+    /// or directly.
+    pub fn generate_declaration(alias_dir: &std::path::Path, project_root: &std::path::Path) -> anyhow::Result<String> {
+        codegen::generate_from_folder(alias_dir, project_root)
+    }
+
+    /// Headless entry point: generates every `.alias` declaration under
+    /// `project_root`, one per line, in name-sorted order.
+    pub fn generate_project_module(project_root: &std::path::Path) -> anyhow::Result<String> {
+        codegen::generate_project_module(project_root)
+    }
+}
+
 /// Wrapper to bridge Entity<AliasEditor> to EditorInstance trait
 #[derive(Clone)]
 pub struct AliasEditorWrapper {
     panel: Entity<AliasEditor>,
     file_path: std::path::PathBuf,
+    /// Every currently-open alias-editor panel (this one included), shared
+    /// with [`AliasEditorPlugin`], so a successful save can tell the others
+    /// to refresh in case this file was one of their alias-of-alias
+    /// dependencies.
+    open_panels: Arc<Mutex<Vec<Entity<AliasEditor>>>>,
 }
 
 impl plugin_editor_api::EditorInstance for AliasEditorWrapper {
@@ -186,7 +245,19 @@ impl plugin_editor_api::EditorInstance for AliasEditorWrapper {
     fn save(&mut self, window: &mut Window, cx: &mut App) -> Result<(), PluginError> {
         self.panel.update(cx, |panel, cx| {
             panel.plugin_save(window, cx)
-        })
+        })?;
+
+        // This document may be another open document's alias-of-alias
+        // dependency; re-resolve everyone else now that it's changed on
+        // disk instead of leaving them stale until they're reopened.
+        let saved_id = self.panel.entity_id();
+        for other in self.open_panels.lock().unwrap().iter() {
+            if other.entity_id() == saved_id {
+                continue;
+            }
+            other.update(cx, |panel, cx| panel.refresh_registry(cx));
+        }
+        Ok(())
     }
 
     fn reload(&mut self, window: &mut Window, cx: &mut App) -> Result<(), PluginError> {
@@ -204,5 +275,34 @@ impl plugin_editor_api::EditorInstance for AliasEditorWrapper {
     }
 }
 
+impl AliasEditorWrapper {
+    /// The aggregated background-validation diagnostics for this document,
+    /// for a host-level problems list. Not part of `EditorInstance` itself
+    /// since it's specific to this plugin; reach it via
+    /// `EditorInstance::as_any().downcast_ref::<AliasEditorWrapper>()`.
+    pub fn problems(&self, cx: &App) -> Vec<diagnostics::BlockDiagnostic> {
+        self.panel.read(cx).problems(cx)
+    }
+
+    /// A cycle or missing reference found while resolving this document's
+    /// alias-of-alias blocks, if any.
+    pub fn resolution_error(&self, cx: &App) -> Option<alias_registry::AliasResolutionError> {
+        self.panel.read(cx).resolution_error().cloned()
+    }
+
+    /// The `type Name = Target;` declaration this document currently
+    /// produces, without opening the GUI.
+    pub fn generated_source(&self, cx: &App) -> String {
+        self.panel.read(cx).generated_declaration(cx)
+    }
+
+    /// [`Self::generated_source`], wrapped as a ```` ```rust ... ``` ````
+    /// fenced code block, matching the editor's "copy as fenced code block"
+    /// action.
+    pub fn generated_source_fenced(&self, cx: &App) -> String {
+        self.panel.read(cx).generated_source_fenced(cx)
+    }
+}
+
 // Export the plugin using the provided macro
 export_plugin!(AliasEditorPlugin);
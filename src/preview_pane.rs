@@ -0,0 +1,98 @@
+//! Read-only preview pane showing the Rust source the block graph
+//! currently produces, with a one-click "copy as fenced code block" action.
+//!
+//! The source is tagged with [`PREVIEW_LANGUAGE`] (used for the fenced
+//! code block's language tag and shown as a label above the pane) but
+//! rendered as plain monospace text; there's no tokenizer or colorizer
+//! here, so this is a *language-tagged* preview, not syntax-highlighted one.
+
+use gpui::*;
+
+/// Language tag shown above the (plain-text) preview and used for the
+/// fenced code block written to the clipboard. Not used for syntax
+/// highlighting; the preview has none.
+pub const PREVIEW_LANGUAGE: &str = "rust";
+
+/// Renders the live-updating generated source for a document.
+pub struct GeneratedCodePreview {
+    source: String,
+    focus_handle: FocusHandle,
+}
+
+impl GeneratedCodePreview {
+    pub fn new(cx: &mut Context<Self>) -> Self {
+        Self {
+            source: String::new(),
+            focus_handle: cx.focus_handle(),
+        }
+    }
+
+    /// Called whenever the block graph or generics change so the preview
+    /// stays live.
+    pub fn set_source(&mut self, source: String, cx: &mut Context<Self>) {
+        if self.source != source {
+            self.source = source;
+            cx.notify();
+        }
+    }
+
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// The source wrapped as a ```` ```rust ... ``` ```` fenced block.
+    pub fn as_fenced_code_block(&self) -> String {
+        format!("```{}\n{}\n```", PREVIEW_LANGUAGE, self.source)
+    }
+
+    fn copy_fenced(&self, cx: &mut Context<Self>) {
+        cx.write_to_clipboard(ClipboardItem::new_string(self.as_fenced_code_block()));
+    }
+}
+
+impl Focusable for GeneratedCodePreview {
+    fn focus_handle(&self, _cx: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for GeneratedCodePreview {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        div()
+            .track_focus(&self.focus_handle)
+            .flex()
+            .flex_col()
+            .size_full()
+            .bg(rgb(0x18181B))
+            .child(
+                div()
+                    .flex()
+                    .justify_between()
+                    .items_center()
+                    .px_2()
+                    .py_1()
+                    .child(format!("preview · {}", PREVIEW_LANGUAGE))
+                    .child(
+                        div()
+                            .px_2()
+                            .py(px(2.0))
+                            .rounded_md()
+                            .hover(|s| s.bg(rgb(0x2A2A2E)))
+                            .on_mouse_down(
+                                MouseButton::Left,
+                                cx.listener(|this, _, _, cx| {
+                                    this.copy_fenced(cx);
+                                }),
+                            )
+                            .child("Copy as fenced code block"),
+                    ),
+            )
+            .child(
+                div()
+                    .flex_1()
+                    .p_2()
+                    .font_family("monospace")
+                    .child(self.source.clone()),
+            )
+    }
+}
@@ -0,0 +1,158 @@
+//! Core data model for a single node in the type-alias block graph.
+
+use serde::{Deserialize, Serialize};
+
+use crate::alias_registry::{AliasRegistry, AliasResolutionError};
+
+/// Whether a leaf block's label is a literal type fragment or the name of
+/// another project `.alias`. The serialized document only ever stores the
+/// label itself (`alias.json` has no notion of this distinction); it's
+/// classified at resolution time by checking the project's
+/// [`AliasRegistry`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TypeRef {
+    Concrete(String),
+    AliasName(String),
+}
+
+/// Stable identifier for a [`TypeBlock`] within a single open document.
+///
+/// IDs are assigned sequentially as blocks are created and are never reused
+/// for the lifetime of the document, so a stale reference (e.g. left over in
+/// drag state after a block is deleted) can be detected instead of silently
+/// resolving to the wrong block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct BlockId(pub u64);
+
+impl BlockId {
+    /// Allocates the next unused id from a per-document counter.
+    pub fn next(counter: &mut u64) -> Self {
+        let id = *counter;
+        *counter += 1;
+        BlockId(id)
+    }
+}
+
+/// A single node in the visual type composition graph.
+///
+/// A block is either a leaf (a concrete type fragment such as `i32` or
+/// `Vec<`) or a container that wraps other blocks by [`BlockId`], so the
+/// graph can be serialized without embedding owned subtrees.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TypeBlock {
+    pub id: BlockId,
+    pub label: String,
+    pub position: (f32, f32),
+    pub children: Vec<BlockId>,
+}
+
+impl TypeBlock {
+    pub fn new(id: BlockId, label: impl Into<String>, position: (f32, f32)) -> Self {
+        Self {
+            id,
+            label: label.into(),
+            position,
+            children: Vec::new(),
+        }
+    }
+
+    /// Renders this block and its children, in order, as a Rust type
+    /// fragment. Containers are expected to have a label ending in `<` and
+    /// a matching `>` is appended once all children are rendered.
+    pub fn render_fragment(&self, all: &std::collections::HashMap<BlockId, TypeBlock>) -> String {
+        if self.children.is_empty() {
+            return self.label.clone();
+        }
+        let inner = self
+            .children
+            .iter()
+            .filter_map(|id| all.get(id))
+            .map(|child| child.render_fragment(all))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("{}{}>", self.label, inner)
+    }
+
+    /// Classifies this block's label against `registry`: a leaf whose label
+    /// matches a known alias name is a reference to it, otherwise it's a
+    /// literal type fragment.
+    ///
+    /// `locals` is the current document's own declared generic parameter
+    /// names (e.g. `["T", "E"]`). A label matching one of them is always a
+    /// local generic, never a reference, even if a project alias happens to
+    /// share its name (a `T`/`E`/`N` alias is exactly the kind of name
+    /// `ConstructorPalette::generic_options` offers for a document's own
+    /// generics).
+    pub fn classify(&self, registry: &AliasRegistry, locals: &[String]) -> TypeRef {
+        if self.children.is_empty()
+            && !locals.iter().any(|local| local == &self.label)
+            && registry.get(&self.label).is_some()
+        {
+            TypeRef::AliasName(self.label.clone())
+        } else {
+            TypeRef::Concrete(self.label.clone())
+        }
+    }
+
+    /// Like [`Self::render_fragment`], but expands any leaf block that names
+    /// another alias to its fully-resolved concrete type via `registry`,
+    /// surfacing a cycle as an error instead of recursing forever. See
+    /// [`Self::classify`] for what `locals` excludes from substitution.
+    pub fn render_fragment_resolved(
+        &self,
+        all: &std::collections::HashMap<BlockId, TypeBlock>,
+        registry: &AliasRegistry,
+        locals: &[String],
+    ) -> Result<String, AliasResolutionError> {
+        if self.children.is_empty() {
+            return match self.classify(registry, locals) {
+                TypeRef::AliasName(name) => registry.resolve(&name),
+                // Block composition only ever records one flat string per
+                // document (see `AliasDocument::target`), so a container
+                // that embeds an alias reference without its own child
+                // block (e.g. a reloaded `Vec<UserId>` leaf) still needs
+                // its embedded tokens resolved, not just a whole-label
+                // match.
+                TypeRef::Concrete(label) => registry.resolve_in_text(&label, locals),
+            };
+        }
+        let mut inner = Vec::with_capacity(self.children.len());
+        for child in self.children.iter().filter_map(|id| all.get(id)) {
+            inner.push(child.render_fragment_resolved(all, registry, locals)?);
+        }
+        Ok(format!("{}{}>", self.label, inner.join(", ")))
+    }
+
+    /// Like [`Self::render_fragment_resolved`], but also records the byte
+    /// range each block occupies in `out` via `record`, so a compiler
+    /// diagnostic's byte span on the registry-resolved text (what's actually
+    /// sent to `rustc`) can be mapped back to the originating block.
+    pub fn render_fragment_resolved_spanned(
+        &self,
+        all: &std::collections::HashMap<BlockId, TypeBlock>,
+        registry: &AliasRegistry,
+        locals: &[String],
+        out: &mut String,
+        record: &mut impl FnMut(BlockId, std::ops::Range<usize>),
+    ) -> Result<(), AliasResolutionError> {
+        let start = out.len();
+        if self.children.is_empty() {
+            let resolved = match self.classify(registry, locals) {
+                TypeRef::AliasName(name) => registry.resolve(&name)?,
+                TypeRef::Concrete(label) => registry.resolve_in_text(&label, locals)?,
+            };
+            out.push_str(&resolved);
+        } else {
+            out.push_str(&self.label);
+            for (i, child) in self.children.iter().filter_map(|id| all.get(id)).enumerate() {
+                if i > 0 {
+                    out.push_str(", ");
+                }
+                child.render_fragment_resolved_spanned(all, registry, locals, out, record)?;
+            }
+            out.push('>');
+        }
+        record(self.id, start..out.len());
+        Ok(())
+    }
+}
@@ -0,0 +1,97 @@
+//! The built-in palette of primitive and standard-library type fragments
+//! that can be dragged onto the [`crate::block_canvas::BlockCanvas`].
+
+use gpui::*;
+
+use crate::generics::GenericParam;
+
+/// A single draggable entry in the palette.
+#[derive(Debug, Clone)]
+pub struct TypeItem {
+    pub label: String,
+    pub description: String,
+}
+
+impl TypeItem {
+    pub fn new(label: impl Into<String>, description: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            description: description.into(),
+        }
+    }
+
+    /// A palette entry for a generic parameter declared on the current
+    /// document, so e.g. `T` can be dragged onto the canvas just like a
+    /// built-in primitive.
+    pub fn from_generic(param: &GenericParam) -> Self {
+        Self::new(param.name.clone(), "Generic parameter declared on this alias")
+    }
+}
+
+fn builtin_items() -> Vec<TypeItem> {
+    vec![
+        TypeItem::new("i32", "32-bit signed integer"),
+        TypeItem::new("u64", "64-bit unsigned integer"),
+        TypeItem::new("String", "Owned, growable UTF-8 string"),
+        TypeItem::new("bool", "Boolean"),
+        TypeItem::new("Vec<", "Growable array"),
+        TypeItem::new("Option<", "Optional value"),
+        TypeItem::new("Result<", "Success or error"),
+    ]
+}
+
+/// Sidebar listing draggable [`TypeItem`]s a document can compose with.
+pub struct TypeLibraryPalette {
+    builtin: Vec<TypeItem>,
+    /// Generics declared on the document currently open in this editor,
+    /// offered as draggable leaf types alongside the built-ins.
+    document_generics: Vec<TypeItem>,
+    focus_handle: FocusHandle,
+}
+
+impl TypeLibraryPalette {
+    pub fn new(cx: &mut Context<Self>) -> Self {
+        Self {
+            builtin: builtin_items(),
+            document_generics: Vec::new(),
+            focus_handle: cx.focus_handle(),
+        }
+    }
+
+    pub fn items(&self) -> impl Iterator<Item = &TypeItem> {
+        self.builtin.iter().chain(self.document_generics.iter())
+    }
+
+    /// Replaces the document-generics section of the palette, e.g. after
+    /// the user adds or removes a `<T, E>` parameter on the current alias.
+    pub fn set_document_generics(&mut self, generics: &[GenericParam]) {
+        self.document_generics = generics.iter().map(TypeItem::from_generic).collect();
+    }
+}
+
+impl Focusable for TypeLibraryPalette {
+    fn focus_handle(&self, _cx: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for TypeLibraryPalette {
+    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
+        div()
+            .track_focus(&self.focus_handle)
+            .flex()
+            .flex_col()
+            .gap_1()
+            .p_2()
+            .children(self.items().map(|item| {
+                div()
+                    .flex()
+                    .flex_col()
+                    .px_2()
+                    .py_1()
+                    .rounded_md()
+                    .hover(|s| s.bg(rgb(0x2A2A2E)))
+                    .child(item.label.clone())
+            }))
+    }
+}
@@ -0,0 +1,374 @@
+//! The top-level editor view: owns the canvas and palettes, and knows how
+//! to load/save an `.alias` document's `alias.json`.
+
+use std::fs;
+use std::path::PathBuf;
+
+use gpui::*;
+use plugin_editor_api::PluginError;
+use serde_json::json;
+use ui::dock::{Panel, PanelEvent};
+
+use crate::alias_document::AliasDocument;
+use crate::alias_registry::{AliasRegistry, AliasResolutionError};
+use crate::block_canvas::BlockCanvas;
+use crate::constructor_palette::{ConstructorPalette, GenericParamAdded, TypeSelected};
+use crate::diagnostics::{BlockDiagnostic, DiagnosticsEngine};
+use crate::generics::{render_param_list, render_where_clause, GenericParam};
+use crate::preview_pane::GeneratedCodePreview;
+use crate::type_palette::TypeLibraryPalette;
+
+/// Emitted when the user asks to insert a new type block and the host
+/// should surface the [`ConstructorPalette`] as a picker.
+#[derive(Debug, Clone)]
+pub struct ShowTypePickerRequest;
+
+/// Visual block-based editor for a single `.alias` document.
+pub struct VisualAliasEditor {
+    file_path: PathBuf,
+    name: String,
+    generics: Vec<GenericParam>,
+    where_clauses: Vec<String>,
+    canvas: Entity<BlockCanvas>,
+    palette: Entity<TypeLibraryPalette>,
+    preview: Entity<GeneratedCodePreview>,
+    diagnostics: Entity<DiagnosticsEngine>,
+    registry: AliasRegistry,
+    resolution_error: Option<AliasResolutionError>,
+    constructor: Entity<ConstructorPalette>,
+    /// Whether the [`ConstructorPalette`] picker is currently shown,
+    /// toggled by the "+ Block" button in the toolbar.
+    show_constructor: bool,
+    focus_handle: FocusHandle,
+}
+
+impl VisualAliasEditor {
+    pub fn new_with_file(file_path: PathBuf, window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let doc = Self::load_document(&file_path).unwrap_or_else(|_| AliasDocument {
+            name: "NewAlias".into(),
+            target: "i32".into(),
+            generics: Vec::new(),
+            where_clauses: Vec::new(),
+        });
+
+        let canvas = cx.new(|cx| {
+            let mut canvas = BlockCanvas::new(cx);
+            canvas.insert_root(doc.target.clone(), (16.0, 16.0));
+            canvas
+        });
+        let palette = cx.new(|cx| {
+            let mut palette = TypeLibraryPalette::new(cx);
+            palette.set_document_generics(&doc.generics);
+            palette
+        });
+        let scratch_dir = file_path
+            .parent()
+            .unwrap_or_else(|| std::path::Path::new("."))
+            .join(".alias-scratch");
+        let diagnostics = cx.new(|_| DiagnosticsEngine::new(scratch_dir));
+        let preview = cx.new(|cx| GeneratedCodePreview::new(cx));
+        let project_root = file_path
+            .parent()
+            .and_then(|alias_dir| alias_dir.parent())
+            .unwrap_or_else(|| std::path::Path::new("."));
+        let registry = AliasRegistry::scan_project(project_root);
+        let constructor = cx.new(|cx| ConstructorPalette::new(cx));
+        let _ = window;
+
+        cx.observe(&diagnostics, |this, diagnostics, cx| {
+            let diagnostics = diagnostics.read(cx).diagnostics().to_vec();
+            this.canvas.update(cx, |canvas, cx| {
+                canvas.set_diagnostics(&diagnostics);
+                cx.notify();
+            });
+        })
+        .detach();
+
+        cx.subscribe(&constructor, |this, _constructor, event: &TypeSelected, cx| {
+            this.canvas.update(cx, |canvas, cx| {
+                canvas.insert_root(event.label.clone(), (16.0, 16.0));
+                cx.notify();
+            });
+            this.show_constructor = false;
+            this.revalidate(cx);
+        })
+        .detach();
+
+        cx.subscribe(&constructor, |this, _constructor, event: &GenericParamAdded, cx| {
+            this.show_constructor = false;
+            this.add_generic(event.0.clone(), cx);
+        })
+        .detach();
+
+        let mut editor = Self {
+            file_path,
+            name: doc.name,
+            generics: doc.generics,
+            where_clauses: doc.where_clauses,
+            canvas,
+            palette,
+            preview,
+            diagnostics,
+            registry,
+            resolution_error: None,
+            constructor,
+            show_constructor: false,
+            focus_handle: cx.focus_handle(),
+        };
+        editor.revalidate(cx);
+        editor
+    }
+
+    /// Toggles the [`ConstructorPalette`] picker open or closed, and notifies
+    /// any host listening for [`ShowTypePickerRequest`] (e.g. to surface it
+    /// as a modal instead of the inline panel rendered below).
+    pub fn toggle_constructor(&mut self, cx: &mut Context<Self>) {
+        self.show_constructor = !self.show_constructor;
+        cx.emit(ShowTypePickerRequest);
+        cx.notify();
+    }
+
+    /// Kicks off (or restarts) background validation of the currently
+    /// generated declaration against the blocks on the canvas.
+    ///
+    /// The declaration sent to `rustc` uses the fully alias-resolved target
+    /// (so it's valid standalone Rust); `alias.json` itself keeps storing
+    /// the unresolved form with bare alias names, per [`AliasRegistry`].
+    pub fn revalidate(&mut self, cx: &mut Context<Self>) {
+        let canvas = self.canvas.read(cx);
+        let locals = self.generic_names();
+        match canvas.render_type_expr_resolved_spanned(&self.registry, &locals) {
+            Ok((target, mut spans)) => {
+                self.resolution_error = None;
+                // `target` is already fully resolved, so an empty registry
+                // is passed and this call's own registry lookup is a no-op;
+                // it's reused purely for the declaration-assembly
+                // formatting shared with `codegen`.
+                // `spans` was recorded against the bare `target` fragment,
+                // so it has to be shifted by the prefix length to line up
+                // with byte offsets into the full `declaration` below, which
+                // is the text actually written to the scratch file.
+                let declaration = crate::codegen::generate_declaration_with_prefix_len(
+                    &self.name,
+                    &target,
+                    &self.generics,
+                    &self.where_clauses,
+                    &AliasRegistry::default(),
+                )
+                .map(|(declaration, prefix_len)| {
+                    spans.shift(prefix_len);
+                    declaration
+                })
+                .unwrap_or(target);
+                self.diagnostics.update(cx, |diagnostics, cx| {
+                    diagnostics.revalidate(declaration, spans, cx);
+                });
+            }
+            Err(err) => {
+                // An unresolved (or cyclic) alias reference means there's
+                // nothing valid to hand to rustc yet; surface it directly
+                // instead of spawning a doomed child process.
+                self.resolution_error = Some(err);
+            }
+        }
+        let source = self.generated_declaration(cx);
+        self.preview.update(cx, |preview, cx| {
+            preview.set_source(source, cx);
+        });
+    }
+
+    /// A cycle or missing reference found while resolving alias-of-alias
+    /// blocks on the last [`Self::revalidate`] pass, if any.
+    pub fn resolution_error(&self) -> Option<&AliasResolutionError> {
+        self.resolution_error.as_ref()
+    }
+
+    /// Re-scans the project for `.alias` definitions and re-resolves,
+    /// picking up edits made to a dependency since this document loaded.
+    pub fn refresh_registry(&mut self, cx: &mut Context<Self>) {
+        let project_root = self
+            .file_path
+            .parent()
+            .and_then(|alias_dir| alias_dir.parent())
+            .map(|p| p.to_path_buf())
+            .unwrap_or_else(|| PathBuf::from("."));
+        self.registry = AliasRegistry::scan_project(&project_root);
+        self.revalidate(cx);
+    }
+
+    /// The aggregated diagnostics from the most recent validation pass,
+    /// for a host-level problems list.
+    pub fn problems(&self, cx: &App) -> Vec<BlockDiagnostic> {
+        self.diagnostics.read(cx).diagnostics().to_vec()
+    }
+
+    fn load_document(file_path: &PathBuf) -> anyhow::Result<AliasDocument> {
+        let contents = fs::read_to_string(file_path)?;
+        Ok(serde_json::from_str(&contents)?)
+    }
+
+    /// Renders the `Target` half of `type Name = Target;` from the canvas,
+    /// with alias-of-alias blocks left unresolved (bare names, as stored in
+    /// `alias.json`). Use [`Self::generated_declaration`] for the text
+    /// that's actually valid standalone Rust.
+    pub fn generated_target(&self, cx: &App) -> String {
+        self.canvas.read(cx).render_type_expr()
+    }
+
+    /// Renders the full `type Name<...> = Target where ...;` declaration
+    /// this document currently produces, including any declared generics
+    /// and `where` bounds. Resolves alias-of-alias blocks through
+    /// `self.registry` the same way [`Self::revalidate`] does before
+    /// sending the declaration to `rustc`, so the preview pane never shows
+    /// a different (possibly non-compiling) target than the one diagnostics
+    /// were computed against.
+    pub fn generated_declaration(&self, cx: &App) -> String {
+        let canvas = self.canvas.read(cx);
+        let locals = self.generic_names();
+        let target = canvas
+            .render_type_expr_resolved(&self.registry, &locals)
+            .unwrap_or_else(|_| canvas.render_type_expr());
+        crate::codegen::generate_declaration(
+            &self.name,
+            &target,
+            &self.generics,
+            &self.where_clauses,
+            &AliasRegistry::default(),
+        )
+        .unwrap_or_else(|_| {
+            format!(
+                "type {}{} = {}{};",
+                self.name,
+                render_param_list(&self.generics),
+                target,
+                render_where_clause(&self.generics, &self.where_clauses),
+            )
+        })
+    }
+
+    pub fn generics(&self) -> &[GenericParam] {
+        &self.generics
+    }
+
+    /// This document's own declared generic parameter names, excluded from
+    /// alias-reference substitution when resolving its target (see
+    /// [`crate::type_block::TypeBlock::classify`]).
+    fn generic_names(&self) -> Vec<String> {
+        self.generics.iter().map(|g| g.name.clone()).collect()
+    }
+
+    /// The generated source as a ```` ```rust ... ``` ```` fenced block,
+    /// giving other plugins a stable text representation of this `.alias`
+    /// without opening the GUI.
+    pub fn generated_source_fenced(&self, cx: &App) -> String {
+        self.preview.read(cx).as_fenced_code_block()
+    }
+
+    /// Declares a new generic parameter and offers it as a draggable leaf
+    /// type in the palette.
+    pub fn add_generic(&mut self, param: GenericParam, cx: &mut Context<Self>) {
+        self.generics.push(param);
+        self.palette.update(cx, |palette, cx| {
+            palette.set_document_generics(&self.generics);
+            cx.notify();
+        });
+        self.revalidate(cx);
+    }
+
+    pub fn remove_generic(&mut self, name: &str, cx: &mut Context<Self>) {
+        self.generics.retain(|g| g.name != name);
+        self.palette.update(cx, |palette, cx| {
+            palette.set_document_generics(&self.generics);
+            cx.notify();
+        });
+        self.revalidate(cx);
+    }
+
+    pub fn set_where_clauses(&mut self, bounds: Vec<String>, cx: &mut Context<Self>) {
+        self.where_clauses = bounds;
+        self.revalidate(cx);
+    }
+
+    pub fn plugin_save(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> Result<(), PluginError> {
+        let doc = AliasDocument {
+            name: self.name.clone(),
+            target: self.generated_target(cx),
+            generics: self.generics.clone(),
+            where_clauses: self.where_clauses.clone(),
+        };
+        let serialized = json!(doc).to_string();
+        fs::write(&self.file_path, serialized)
+            .map_err(|e| PluginError::Io(e.to_string()))?;
+        self.revalidate(cx);
+        Ok(())
+    }
+
+    pub fn plugin_reload(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> Result<(), PluginError> {
+        let doc = Self::load_document(&self.file_path).map_err(|e| PluginError::Io(e.to_string()))?;
+        self.name = doc.name;
+        self.generics = doc.generics;
+        self.where_clauses = doc.where_clauses;
+        let target = doc.target;
+        self.canvas.update(cx, |canvas, _cx| {
+            canvas.roots.clear();
+            canvas.blocks.clear();
+            canvas.insert_root(target, (16.0, 16.0));
+        });
+        self.palette.update(cx, |palette, cx| {
+            palette.set_document_generics(&self.generics);
+            cx.notify();
+        });
+        self.revalidate(cx);
+        Ok(())
+    }
+}
+
+impl EventEmitter<ShowTypePickerRequest> for VisualAliasEditor {}
+impl EventEmitter<PanelEvent> for VisualAliasEditor {}
+
+impl Focusable for VisualAliasEditor {
+    fn focus_handle(&self, _cx: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Panel for VisualAliasEditor {
+    fn persistent_name() -> &'static str {
+        "AliasEditor"
+    }
+}
+
+impl Render for VisualAliasEditor {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        div()
+            .track_focus(&self.focus_handle)
+            .flex()
+            .size_full()
+            .child(self.palette.clone())
+            .child(
+                div()
+                    .flex_1()
+                    .flex()
+                    .flex_col()
+                    .child(
+                        div()
+                            .px_2()
+                            .py_1()
+                            .rounded_md()
+                            .hover(|s| s.bg(rgb(0x2A2A2E)))
+                            .on_mouse_down(
+                                MouseButton::Left,
+                                cx.listener(|this, _, _, cx| {
+                                    this.toggle_constructor(cx);
+                                }),
+                            )
+                            .child("+ Block"),
+                    )
+                    .when(self.show_constructor, |el| {
+                        el.child(div().w(px(220.0)).child(self.constructor.clone()))
+                    })
+                    .child(div().flex_1().child(self.canvas.clone())),
+            )
+            .child(div().w(px(360.0)).child(self.preview.clone()))
+    }
+}